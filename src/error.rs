@@ -0,0 +1,66 @@
+use std::fmt;
+
+/// A dependency that was referenced by one or more elements but never added
+/// to the builder itself.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct MissingDependency<K> {
+    /// The key that was referenced as a dependency but never added as an element.
+    pub dependency: K,
+    /// The elements that referenced `dependency`, sorted by key.
+    pub required_by: Vec<K>,
+}
+
+/// The error returned by [`DependenciesBuilder::build`](crate::DependenciesBuilder::build)
+/// when the dependency graph is invalid.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DependencyError<K> {
+    /// One or more elements reference a dependency that was never added.
+    MissingDependencies(Vec<MissingDependency<K>>),
+    /// The dependency graph contains one or more cycles, each given as the
+    /// sequence of element keys visited from (and back to) the cycle's start.
+    CyclicDependencies(Vec<Vec<K>>),
+}
+
+impl<K: fmt::Display> fmt::Display for DependencyError<K> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        match self {
+            DependencyError::MissingDependencies(missing) => {
+                let descriptions: Vec<String> = missing
+                    .iter()
+                    .map(|missing| {
+                        format!(
+                            "{} (required by {})",
+                            missing.dependency,
+                            missing
+                                .required_by
+                                .iter()
+                                .map(|element| element.to_string())
+                                .collect::<Vec<_>>()
+                                .join(", ")
+                        )
+                    })
+                    .collect();
+                write!(
+                    f,
+                    "Some dependencies do not exist as elements: {}",
+                    descriptions.join("; ")
+                )
+            }
+            DependencyError::CyclicDependencies(cycles) => {
+                let descriptions: Vec<String> = cycles
+                    .iter()
+                    .map(|cycle| {
+                        cycle
+                            .iter()
+                            .map(|element| element.to_string())
+                            .collect::<Vec<_>>()
+                            .join(" → ")
+                    })
+                    .collect();
+                write!(f, "Cyclic dependency detected: {}", descriptions.join("; "))
+            }
+        }
+    }
+}
+
+impl<K: fmt::Debug + fmt::Display> std::error::Error for DependencyError<K> {}
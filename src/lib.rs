@@ -17,6 +17,9 @@
 //! - It exposes two structs `DependencyBuilder` and `Dependencies`. First is for building up the list of dependencies
 //! and building (calling the `.build()` function also validates the entire list) the second struct. Second is for
 //! generating tranches of dependencies for deployment hierarchies.
+//! - Both structs are generic over the element key (`K`) and an optional payload (`V`) that travels alongside it,
+//!   so elements can carry arbitrary metadata. `StringDependencies`/`StringDependenciesBuilder` keep the original
+//!   `String`-keyed, payload-less API available under dedicated names.
 //!
 //!
 //!   ```
@@ -44,31 +47,46 @@
 
 use anyhow::{Ok, Result};
 use petgraph::{
-    algo::is_cyclic_directed,
+    algo::{is_cyclic_directed, tarjan_scc},
     graph::{DiGraph, NodeIndex},
-    visit::{IntoNodeReferences, NodeIndexable},
+    visit::IntoNodeReferences,
     Direction, Graph,
 };
+use std::borrow::Borrow;
 use std::collections::{HashMap, HashSet};
+use std::hash::Hash;
 
-pub struct DependenciesBuilder {
-    all_elements: Vec<String>,
-    all_dependencies: Vec<String>,
-    graph: DiGraph<String, ()>,
-    dependency_map: HashMap<String, (NodeIndex, Vec<String>)>,
-}
+mod error;
+pub use error::{DependencyError, MissingDependency};
+
+mod scheduler;
+pub use scheduler::Scheduler;
 
-impl DependenciesBuilder {
-    pub fn add_element(mut self, name: String, dependecies: Vec<String>) -> Self {
-        self.all_dependencies.extend(dependecies.clone());
+/// A [`DependenciesBuilder`] keyed by `String` with no per-element payload,
+/// matching the crate's pre-generic API.
+pub type StringDependenciesBuilder = DependenciesBuilder<String, ()>;
+/// A [`Dependencies`] keyed by `String` with no per-element payload, matching
+/// the crate's pre-generic API.
+pub type StringDependencies = Dependencies<String, ()>;
 
+pub struct DependenciesBuilder<K, V = ()> {
+    graph: DiGraph<(K, V), ()>,
+    dependency_map: HashMap<K, (NodeIndex, Vec<K>)>,
+}
+
+impl<K, V> DependenciesBuilder<K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    /// Adds (or replaces) an element together with the payload attached to it.
+    pub fn add_element_with_payload(mut self, name: K, payload: V, dependencies: Vec<K>) -> Self {
         if let Some((graph_node, _)) = self.dependency_map.get(&name) {
-            self.dependency_map
-                .insert(name, (graph_node.to_owned(), dependecies));
+            let graph_node = *graph_node;
+            self.graph[graph_node] = (name.clone(), payload);
+            self.dependency_map.insert(name, (graph_node, dependencies));
         } else {
-            self.all_elements.push(name.clone());
-            let node = self.graph.add_node(name.clone());
-            self.dependency_map.insert(name, (node, dependecies));
+            let node = self.graph.add_node((name.clone(), payload));
+            self.dependency_map.insert(name, (node, dependencies));
         }
         self
     }
@@ -82,98 +100,381 @@ impl DependenciesBuilder {
         }
     }
 
-    fn dependencies_are_met(&self) -> bool {
-        let elements_set: HashSet<_> = self.all_elements.iter().collect();
-        self.all_dependencies
-            .iter()
-            .all(|dependency| elements_set.contains(dependency))
+    fn missing_dependencies(&self) -> Vec<MissingDependency<K>>
+    where
+        K: Ord,
+    {
+        let mut missing: HashMap<&K, Vec<K>> = HashMap::new();
+        for (element, (_, dependencies)) in &self.dependency_map {
+            for dependency in dependencies {
+                if !self.dependency_map.contains_key(dependency) {
+                    missing.entry(dependency).or_default().push(element.clone());
+                }
+            }
+        }
+
+        let mut missing_dependencies: Vec<MissingDependency<K>> = missing
+            .into_iter()
+            .map(|(dependency, mut required_by)| {
+                required_by.sort();
+                MissingDependency {
+                    dependency: dependency.clone(),
+                    required_by,
+                }
+            })
+            .collect();
+        missing_dependencies.sort_by(|a, b| a.dependency.cmp(&b.dependency));
+        missing_dependencies
     }
 
     fn no_cyclic_dependencies(&self) -> bool {
         !is_cyclic_directed(&self.graph)
     }
 
-    fn validate(&mut self) -> Result<()> {
-        if !self.dependencies_are_met() {
-            return Err(anyhow::anyhow!(
-                "Some dependencies do not exist as elements"
-            ));
+    /// Finds simple cycles in `graph`, expressed as the sequence of node
+    /// keys visited from (and back to) the cycle's start.
+    ///
+    /// Each strongly connected component with more than one node (or a
+    /// single node with a self-edge) is decomposed into simple cycles by
+    /// doing a DFS restricted to the component and emitting a cycle whenever
+    /// the DFS reaches a node already on the current path. Each edge is
+    /// followed at most once, so this reports at least one cycle per cyclic
+    /// component, not every simple cycle in it. Components are visited in the
+    /// order `tarjan_scc` returns them; the start candidates within a
+    /// component are sorted by key, then by `NodeIndex`, so the same graph
+    /// always reports the same cycles, regardless of `HashSet` iteration order.
+    fn find_cycles(graph: &DiGraph<(K, V), ()>) -> Vec<Vec<K>>
+    where
+        K: Ord,
+    {
+        let mut cycles = Vec::new();
+        for component in tarjan_scc(graph) {
+            let is_cyclic_component =
+                component.len() > 1 || graph.find_edge(component[0], component[0]).is_some();
+            if !is_cyclic_component {
+                continue;
+            }
+            let component_nodes: HashSet<NodeIndex> = component.iter().copied().collect();
+            cycles.extend(Self::decompose_into_simple_cycles(graph, &component_nodes));
+        }
+        cycles
+    }
+
+    fn decompose_into_simple_cycles(
+        graph: &DiGraph<(K, V), ()>,
+        component: &HashSet<NodeIndex>,
+    ) -> Vec<Vec<K>>
+    where
+        K: Ord,
+    {
+        let mut covered_edges: HashSet<(NodeIndex, NodeIndex)> = HashSet::new();
+        let mut cycles = Vec::new();
+
+        let mut starts: Vec<NodeIndex> = component.iter().copied().collect();
+        starts.sort_by(|a, b| graph[*a].0.cmp(&graph[*b].0).then_with(|| a.cmp(b)));
+
+        for start in starts {
+            let mut path = vec![start];
+            let mut on_path: HashSet<NodeIndex> = HashSet::from([start]);
+            let mut frames = vec![Self::component_neighbors(graph, start, component)];
+
+            while let Some(frame) = frames.last_mut() {
+                let Some(next) = frame.pop() else {
+                    let node = path.pop().unwrap();
+                    on_path.remove(&node);
+                    frames.pop();
+                    continue;
+                };
+
+                let current = *path.last().unwrap();
+                if covered_edges.contains(&(current, next)) {
+                    continue;
+                }
+
+                if on_path.contains(&next) {
+                    let cycle_start = path.iter().position(|node| *node == next).unwrap();
+                    let mut cycle = path[cycle_start..].to_vec();
+                    cycle.push(next);
+                    for pair in cycle.windows(2) {
+                        covered_edges.insert((pair[0], pair[1]));
+                    }
+                    cycles.push(
+                        cycle
+                            .into_iter()
+                            .map(|node| graph[node].0.clone())
+                            .collect(),
+                    );
+                } else {
+                    covered_edges.insert((current, next));
+                    path.push(next);
+                    on_path.insert(next);
+                    frames.push(Self::component_neighbors(graph, next, component));
+                }
+            }
+        }
+
+        cycles
+    }
+
+    fn component_neighbors(
+        graph: &DiGraph<(K, V), ()>,
+        node: NodeIndex,
+        component: &HashSet<NodeIndex>,
+    ) -> Vec<NodeIndex> {
+        graph
+            .neighbors_directed(node, Direction::Outgoing)
+            .filter(|neighbor| component.contains(neighbor))
+            .collect()
+    }
+
+    fn validate(&mut self) -> Result<(), DependencyError<K>>
+    where
+        K: Ord,
+    {
+        let missing_dependencies = self.missing_dependencies();
+        if !missing_dependencies.is_empty() {
+            return Err(DependencyError::MissingDependencies(missing_dependencies));
         }
         self.add_edges();
         if !self.no_cyclic_dependencies() {
-            return Err(anyhow::anyhow!("Cyclic dependency detected"));
+            return Err(DependencyError::CyclicDependencies(Self::find_cycles(
+                &self.graph,
+            )));
         }
         self.graph.clear_edges();
 
-        Ok(())
+        Result::Ok(())
     }
 
-    pub fn build(&mut self) -> Result<Dependencies> {
+    pub fn build(&mut self) -> Result<Dependencies<K, V>, DependencyError<K>>
+    where
+        K: Ord,
+        V: Clone,
+    {
         self.validate()?;
         self.add_edges();
-        Ok(Dependencies {
+        Result::Ok(Dependencies {
             graph: self.graph.clone(),
         })
     }
 }
 
+impl<K> DependenciesBuilder<K, ()>
+where
+    K: Eq + Hash + Clone,
+{
+    /// Adds (or replaces) an element with no payload attached.
+    pub fn add_element(self, name: K, dependencies: Vec<K>) -> Self {
+        self.add_element_with_payload(name, (), dependencies)
+    }
+}
+
 #[derive(Debug)]
-pub struct Dependencies {
-    graph: DiGraph<String, ()>,
+pub struct Dependencies<K, V = ()> {
+    graph: DiGraph<(K, V), ()>,
 }
 
-impl Dependencies {
-    fn find_node_by_name(graph: Graph<String, ()>, name: &str) -> Option<NodeIndex> {
+impl<K, V> Dependencies<K, V>
+where
+    K: Eq + Hash + Clone,
+    V: Clone,
+{
+    fn find_node_by_name<Q>(graph: &Graph<(K, V), ()>, name: &Q) -> Option<NodeIndex>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized,
+    {
         for (node_index, node_name) in graph.node_references() {
-            if node_name == name {
+            if node_name.0.borrow() == name {
                 return Some(node_index);
             }
         }
         None
     }
 
-    pub fn generate_tranches(&self) -> Result<Vec<Vec<String>>> {
-        let mut tranches: Vec<Vec<String>> = vec![];
-        let mut traverse_graph = self.graph.clone();
-        while traverse_graph.node_count() > 0 {
-            let mut node_to_remove: Vec<(NodeIndex, String)> = vec![];
-            let mut new_layer: Vec<String> = Vec::new();
-            for (node_index, node_name) in traverse_graph.node_references() {
-                if traverse_graph
+    /// Returns the transitive dependencies required to build `target`, ordered so that
+    /// each element depends solely on earlier elements, with `target` itself last.
+    pub fn dependencies_of<Q>(&self, target: &Q) -> Result<Vec<K>>
+    where
+        K: Borrow<Q>,
+        Q: Eq + ?Sized + std::fmt::Display,
+    {
+        let target_node = Self::find_node_by_name(&self.graph, target)
+            .ok_or_else(|| anyhow::anyhow!("Element \"{target}\" not found"))?;
+
+        let mut visited: HashSet<NodeIndex> = HashSet::new();
+        let mut ordered: Vec<K> = Vec::new();
+        self.collect_dependencies_of(target_node, &mut visited, &mut ordered);
+
+        Ok(ordered)
+    }
+
+    /// Iterative post-order DFS: each stack frame pairs a node with an
+    /// iterator over its remaining dependencies, so the traversal depth is
+    /// bounded by heap-allocated frames rather than the native call stack,
+    /// keeping arbitrarily deep dependency chains from overflowing it.
+    fn collect_dependencies_of(
+        &self,
+        node: NodeIndex,
+        visited: &mut HashSet<NodeIndex>,
+        ordered: &mut Vec<K>,
+    ) {
+        if !visited.insert(node) {
+            return;
+        }
+        let mut frames = vec![(
+            node,
+            self.graph.neighbors_directed(node, Direction::Outgoing),
+        )];
+
+        while let Some((node, children)) = frames.last_mut() {
+            let Some(dependency) = children.next() else {
+                let node = *node;
+                frames.pop();
+                ordered.push(self.graph[node].0.clone());
+                continue;
+            };
+
+            if visited.insert(dependency) {
+                frames.push((
+                    dependency,
+                    self.graph
+                        .neighbors_directed(dependency, Direction::Outgoing),
+                ));
+            }
+        }
+    }
+
+    /// Groups elements into tranches (layers) such that every element in a tranche depends
+    /// only on elements in earlier tranches, using Kahn's algorithm: each node's unmet
+    /// dependency count is tracked in a map seeded once up front, and removing a tranche
+    /// only decrements the counts of its direct dependents, so the whole graph is walked
+    /// exactly once rather than being re-scanned and re-cloned per tranche.
+    fn generate_tranches_by_index(&self) -> Vec<Vec<NodeIndex>> {
+        let mut unmet_dependencies: HashMap<NodeIndex, usize> = self
+            .graph
+            .node_indices()
+            .map(|node_index| {
+                let count = self
+                    .graph
                     .neighbors_directed(node_index, Direction::Outgoing)
-                    .count()
-                    == 0
+                    .count();
+                (node_index, count)
+            })
+            .collect();
+
+        let mut tranches: Vec<Vec<NodeIndex>> = Vec::new();
+        let mut current: Vec<NodeIndex> = self
+            .graph
+            .node_indices()
+            .filter(|node_index| unmet_dependencies[node_index] == 0)
+            .collect();
+
+        while !current.is_empty() {
+            let mut next: Vec<NodeIndex> = Vec::new();
+            for &node_index in &current {
+                for dependent in self
+                    .graph
+                    .neighbors_directed(node_index, Direction::Incoming)
                 {
-                    node_to_remove.push((node_index, node_name.to_string()));
+                    let count = unmet_dependencies.get_mut(&dependent).unwrap();
+                    *count -= 1;
+                    if *count == 0 {
+                        next.push(dependent);
+                    }
                 }
             }
-            for (_, node_name) in node_to_remove {
-                let node_index =
-                    Dependencies::find_node_by_name(traverse_graph.clone(), &node_name)
-                        .ok_or(anyhow::anyhow!("Node not found"))?;
-                traverse_graph
-                    .remove_node(traverse_graph.from_index(traverse_graph.to_index(node_index)));
-
-                new_layer.push(node_name.to_string())
-            }
-            tranches.push(new_layer);
+            tranches.push(current);
+            current = next;
         }
-        Ok(tranches)
+
+        tranches
+    }
+
+    pub fn generate_tranches(&self) -> Result<Vec<Vec<K>>> {
+        Ok(self
+            .generate_tranches_by_index()
+            .into_iter()
+            .map(|tranche| {
+                tranche
+                    .into_iter()
+                    .map(|node_index| self.graph[node_index].0.clone())
+                    .collect()
+            })
+            .collect())
     }
 
-    pub fn builder() -> DependenciesBuilder {
+    /// Like [`generate_tranches`](Self::generate_tranches), but also yields each
+    /// element's payload alongside its key.
+    pub fn generate_tranches_with_payloads(&self) -> Result<Vec<Vec<(K, V)>>> {
+        Ok(self
+            .generate_tranches_by_index()
+            .into_iter()
+            .map(|tranche| {
+                tranche
+                    .into_iter()
+                    .map(|node_index| self.graph[node_index].clone())
+                    .collect()
+            })
+            .collect())
+    }
+}
+
+impl<K, V> Dependencies<K, V>
+where
+    K: Eq + Hash + Clone,
+{
+    pub fn builder() -> DependenciesBuilder<K, V> {
         DependenciesBuilder {
-            all_elements: Vec::new(),
-            all_dependencies: Vec::new(),
             graph: DiGraph::new(),
             dependency_map: HashMap::new(),
         }
     }
+
+    /// Converts this dependency graph into a pull-based [`Scheduler`], for executors that
+    /// complete elements out of order and want to unlock dependents as soon as each
+    /// element's prerequisites finish.
+    pub fn into_scheduler(self) -> Scheduler<K> {
+        let mut remaining: HashMap<K, usize> = HashMap::new();
+        let mut dependents: HashMap<K, Vec<K>> = HashMap::new();
+        let mut ready: Vec<K> = Vec::new();
+
+        for (node_index, node_weight) in self.graph.node_references() {
+            let name = node_weight.0.clone();
+            let dependencies: Vec<NodeIndex> = self
+                .graph
+                .neighbors_directed(node_index, Direction::Outgoing)
+                .collect();
+
+            if dependencies.is_empty() {
+                ready.push(name.clone());
+            } else {
+                remaining.insert(name.clone(), dependencies.len());
+            }
+
+            for dependency_index in dependencies {
+                let dependency_name = self.graph[dependency_index].0.clone();
+                dependents
+                    .entry(dependency_name)
+                    .or_default()
+                    .push(name.clone());
+            }
+        }
+
+        let depth = scheduler::compute_depths(&dependents);
+
+        Scheduler {
+            remaining,
+            dependents,
+            ready,
+            depth,
+        }
+    }
 }
 
 #[cfg(test)]
 mod tests {
-    use super::Dependencies;
+    use super::{Dependencies, DependencyError, Scheduler};
 
     #[test]
     fn can_validate_simple_tree() {
@@ -203,10 +504,17 @@ mod tests {
             .add_element("a".to_string(), vec!["b".to_string(), "c".to_string()])
             .add_element("b".to_string(), vec!["c".to_string()]);
 
-        assert_eq!(
-            dependencies_builder.build().unwrap_err().to_string(),
-            "Some dependencies do not exist as elements"
-        );
+        match dependencies_builder.build().unwrap_err() {
+            DependencyError::MissingDependencies(missing) => {
+                assert_eq!(missing.len(), 1);
+                assert_eq!(missing[0].dependency, "c");
+                assert_eq!(
+                    missing[0].required_by,
+                    vec!["a".to_string(), "b".to_string()]
+                );
+            }
+            other => panic!("expected MissingDependencies, got {other:?}"),
+        }
     }
 
     #[test]
@@ -216,10 +524,20 @@ mod tests {
             .add_element("b".to_string(), vec!["c".to_string()])
             .add_element("c".to_string(), vec!["a".to_string(), "b".to_string()]);
 
-        assert_eq!(
-            dependencies_builder.build().unwrap_err().to_string(),
-            "Cyclic dependency detected"
-        );
+        match dependencies_builder.build().unwrap_err() {
+            DependencyError::CyclicDependencies(cycles) => {
+                assert!(!cycles.is_empty());
+                let involved: std::collections::HashSet<&String> =
+                    cycles.iter().flatten().collect();
+                for name in ["a", "b", "c"] {
+                    assert!(
+                        involved.contains(&name.to_string()),
+                        "expected {name} to be part of a reported cycle"
+                    );
+                }
+            }
+            other => panic!("expected CyclicDependencies, got {other:?}"),
+        }
     }
 
     #[test]
@@ -292,4 +610,164 @@ mod tests {
             dependencies.generate_tranches().unwrap().len()
         );
     }
+
+    #[test]
+    fn dependencies_of_orders_a_transitive_chain() {
+        let mut dependencies_builder = Dependencies::builder()
+            .add_element("a".to_string(), vec!["b".to_string()])
+            .add_element("b".to_string(), vec!["c".to_string()])
+            .add_element("c".to_string(), vec![]);
+
+        let dependencies = dependencies_builder.build().unwrap();
+
+        assert_eq!(
+            dependencies.dependencies_of("a").unwrap(),
+            vec!["c".to_string(), "b".to_string(), "a".to_string()]
+        );
+    }
+
+    #[test]
+    fn dependencies_of_excludes_unrelated_elements() {
+        let mut dependencies_builder = Dependencies::builder()
+            .add_element("a".to_string(), vec!["b".to_string()])
+            .add_element("b".to_string(), vec![])
+            .add_element("unrelated".to_string(), vec![]);
+
+        let dependencies = dependencies_builder.build().unwrap();
+
+        assert_eq!(
+            dependencies.dependencies_of("a").unwrap(),
+            vec!["b".to_string(), "a".to_string()]
+        );
+    }
+
+    #[test]
+    fn dependencies_of_deduplicates_a_diamond() {
+        let mut dependencies_builder = Dependencies::builder()
+            .add_element(
+                "a".to_string(),
+                vec!["b".to_string(), "c".to_string(), "d".to_string()],
+            )
+            .add_element("b".to_string(), vec!["d".to_string()])
+            .add_element("c".to_string(), vec!["d".to_string()])
+            .add_element("d".to_string(), vec![]);
+
+        let dependencies = dependencies_builder.build().unwrap();
+        let ordered = dependencies.dependencies_of("a").unwrap();
+
+        assert_eq!(ordered.last(), Some(&"a".to_string()));
+        assert_eq!(ordered.len(), 4);
+        assert!(ordered.iter().position(|name| name == "d").unwrap() < ordered.len() - 1);
+    }
+
+    #[test]
+    fn dependencies_of_errors_for_an_unknown_target() {
+        let mut dependencies_builder = Dependencies::builder().add_element("a".to_string(), vec![]);
+        let dependencies = dependencies_builder.build().unwrap();
+
+        assert!(dependencies.dependencies_of("missing").is_err());
+    }
+
+    #[test]
+    fn carries_a_payload_alongside_each_element() {
+        let mut dependencies_builder = Dependencies::builder()
+            .add_element_with_payload("b".to_string(), 2, vec!["a".to_string()])
+            .add_element_with_payload("a".to_string(), 1, vec![]);
+
+        let dependencies = dependencies_builder.build().unwrap();
+
+        assert_eq!(
+            dependencies.generate_tranches_with_payloads().unwrap(),
+            vec![vec![("a".to_string(), 1)], vec![("b".to_string(), 2)]]
+        );
+    }
+
+    #[test]
+    fn supports_non_string_keys() {
+        let mut dependencies_builder = Dependencies::<i32>::builder()
+            .add_element(1, vec![2])
+            .add_element(2, vec![]);
+
+        let dependencies = dependencies_builder.build().unwrap();
+
+        assert_eq!(
+            dependencies.generate_tranches().unwrap(),
+            vec![vec![2], vec![1]]
+        );
+    }
+
+    #[test]
+    fn scheduler_unlocks_dependents_as_they_are_marked_done() {
+        let mut dependencies_builder = Dependencies::builder()
+            .add_element("b".to_string(), vec!["d".to_string()])
+            .add_element("c".to_string(), vec!["d".to_string()])
+            .add_element(
+                "a".to_string(),
+                vec!["d".to_string(), "e".to_string(), "y".to_string()],
+            )
+            .add_element("d".to_string(), vec!["e".to_string()])
+            .add_element("e".to_string(), vec![])
+            .add_element("y".to_string(), vec![]);
+
+        let dependencies = dependencies_builder.build().unwrap();
+        let mut scheduler = dependencies.into_scheduler();
+
+        assert_eq!(
+            scheduler.next_ready(),
+            vec!["e".to_string(), "y".to_string()]
+        );
+        assert!(scheduler.next_ready().is_empty());
+
+        scheduler.mark_done("y");
+        assert!(scheduler.next_ready().is_empty());
+
+        scheduler.mark_done("e");
+        assert_eq!(scheduler.next_ready(), vec!["d".to_string()]);
+
+        scheduler.mark_done("d");
+        assert_eq!(
+            scheduler.next_ready(),
+            vec!["a".to_string(), "b".to_string(), "c".to_string()]
+        );
+
+        scheduler.mark_done("a");
+        scheduler.mark_done("b");
+        scheduler.mark_done("c");
+        assert!(scheduler.ensure_no_pending().is_ok());
+    }
+
+    #[test]
+    fn scheduler_errors_when_nothing_is_ready_but_items_remain() {
+        // Builds a stuck scheduler directly: "a" still waits on one dependency that
+        // nothing will mark done, and no element is ready.
+        let scheduler = Scheduler {
+            remaining: std::collections::HashMap::from([("a".to_string(), 1)]),
+            dependents: std::collections::HashMap::new(),
+            ready: vec![],
+            depth: std::collections::HashMap::new(),
+        };
+
+        assert!(scheduler.ensure_no_pending().is_err());
+    }
+
+    #[test]
+    fn scheduler_prioritizes_deeper_elements_over_alphabetical_order() {
+        // "z" sits under a two-level chain of dependents (p, then q) while "m" only has
+        // one (n). Both are ready immediately, and "m" would sort first alphabetically,
+        // but "z" has the longer remaining critical path and should come first.
+        let mut dependencies_builder = Dependencies::builder()
+            .add_element("q".to_string(), vec!["p".to_string()])
+            .add_element("p".to_string(), vec!["z".to_string()])
+            .add_element("z".to_string(), vec![])
+            .add_element("n".to_string(), vec!["m".to_string()])
+            .add_element("m".to_string(), vec![]);
+
+        let dependencies = dependencies_builder.build().unwrap();
+        let mut scheduler = dependencies.into_scheduler();
+
+        assert_eq!(
+            scheduler.next_ready(),
+            vec!["z".to_string(), "m".to_string()]
+        );
+    }
 }
@@ -0,0 +1,146 @@
+use anyhow::Result;
+use std::borrow::Borrow;
+use std::collections::HashMap;
+use std::hash::Hash;
+
+/// A pull-based scheduler for elements whose dependencies get satisfied out of order,
+/// built from [`Dependencies::into_scheduler`](crate::Dependencies::into_scheduler).
+///
+/// Unlike [`Dependencies::generate_tranches`](crate::Dependencies::generate_tranches), which
+/// precomputes a static, fully-layered plan, a `Scheduler` unlocks each element the moment
+/// its prerequisites finish, which suits concurrent executors that complete work at
+/// different speeds.
+pub struct Scheduler<K> {
+    pub(crate) remaining: HashMap<K, usize>,
+    pub(crate) dependents: HashMap<K, Vec<K>>,
+    pub(crate) ready: Vec<K>,
+    /// For each element, the length of the longest remaining chain of dependents that
+    /// still need it, computed once up front over the full (pre-completion) graph.
+    pub(crate) depth: HashMap<K, usize>,
+}
+
+/// Computes, for every key appearing in `dependents`, the length of the longest path of
+/// dependents that still need it, via a memoized DFS over the reverse (dependents) graph.
+pub(crate) fn compute_depths<K>(dependents: &HashMap<K, Vec<K>>) -> HashMap<K, usize>
+where
+    K: Eq + Hash + Clone,
+{
+    let mut depths: HashMap<K, usize> = HashMap::new();
+    let keys: Vec<K> = dependents.keys().cloned().collect();
+    for key in keys {
+        depth_of(&key, dependents, &mut depths);
+    }
+    depths
+}
+
+/// Iterative post-order DFS: each stack frame pairs a key with an iterator over its
+/// remaining dependents, so traversal depth is bounded by heap-allocated frames rather
+/// than the native call stack, keeping arbitrarily deep dependency chains from
+/// overflowing it.
+fn depth_of<K>(key: &K, dependents: &HashMap<K, Vec<K>>, depths: &mut HashMap<K, usize>) -> usize
+where
+    K: Eq + Hash + Clone,
+{
+    if let Some(&depth) = depths.get(key) {
+        return depth;
+    }
+
+    // Guard against revisiting a key while it's already on the current DFS path; the
+    // underlying graph is acyclic, so this only protects against a would-be self-loop.
+    depths.insert(key.clone(), 0);
+    let mut frames = vec![(
+        key.clone(),
+        dependents.get(key).map(Vec::as_slice).unwrap_or(&[]).iter(),
+    )];
+
+    while let Some((current, children)) = frames.last_mut() {
+        let Some(child) = children.next() else {
+            let current = current.clone();
+            let depth = dependents
+                .get(&current)
+                .map(|children| {
+                    children
+                        .iter()
+                        .map(|child| 1 + depths.get(child).copied().unwrap_or(0))
+                        .max()
+                        .unwrap_or(0)
+                })
+                .unwrap_or(0);
+            depths.insert(current, depth);
+            frames.pop();
+            continue;
+        };
+
+        if depths.contains_key(child) {
+            continue;
+        }
+        depths.insert(child.clone(), 0);
+        frames.push((
+            child.clone(),
+            dependents
+                .get(child)
+                .map(Vec::as_slice)
+                .unwrap_or(&[])
+                .iter(),
+        ));
+    }
+
+    depths.get(key).copied().unwrap_or(0)
+}
+
+impl<K> Scheduler<K>
+where
+    K: Eq + Hash + Clone,
+{
+    /// Returns every element whose dependencies are currently satisfied and that has not
+    /// already been handed out, then clears them from the ready set. Elements on the
+    /// longest remaining critical path are returned first, so a concurrent executor can
+    /// start them as early as possible; ties break by key for determinism.
+    pub fn next_ready(&mut self) -> Vec<K>
+    where
+        K: Ord,
+    {
+        let mut ready = std::mem::take(&mut self.ready);
+        ready.sort_by(|a, b| {
+            let depth_a = self.depth.get(a).copied().unwrap_or(0);
+            let depth_b = self.depth.get(b).copied().unwrap_or(0);
+            depth_b.cmp(&depth_a).then_with(|| a.cmp(b))
+        });
+        ready
+    }
+
+    /// Marks `name` as completed, decrementing the outstanding dependency count of every
+    /// element that depends on it and moving any that reach zero into the ready set.
+    pub fn mark_done<Q>(&mut self, name: &Q)
+    where
+        K: Borrow<Q>,
+        Q: Hash + Eq + ?Sized,
+    {
+        let Some(dependents) = self.dependents.remove(name) else {
+            return;
+        };
+        for dependent in dependents {
+            if let Some(count) = self.remaining.get_mut::<K>(&dependent) {
+                *count -= 1;
+                if *count == 0 {
+                    self.remaining.remove::<K>(&dependent);
+                    self.ready.push(dependent);
+                }
+            }
+        }
+    }
+
+    /// Errors if elements remain unscheduled while nothing is ready: either the
+    /// underlying dependency graph has a cycle, or some element they wait on has
+    /// not been marked done (including elements handed out by `next_ready` that
+    /// are still in progress).
+    pub fn ensure_no_pending(&self) -> Result<()> {
+        if self.ready.is_empty() && !self.remaining.is_empty() {
+            return Err(anyhow::anyhow!(
+                "{} element(s) remain but none are ready; a cycle or an element not marked done is blocking them",
+                self.remaining.len()
+            ));
+        }
+        Ok(())
+    }
+}